@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+use super::HunterClient;
+use crate::error::HunterError;
+
+impl HunterClient {
+    /// Fetch the account's remaining request quota.
+    pub async fn account(&self) -> Result<Account, HunterError> {
+        let url = self.url("account", &[]);
+
+        self.get(url).await
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Account {
+    pub data: AccountData,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AccountData {
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub email: String,
+    pub plan_name: String,
+    pub plan_level: usize,
+    pub reset_date: String,
+    pub calls: AccountCalls,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AccountCalls {
+    pub used: usize,
+    pub available: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn client_for(server: &MockServer) -> HunterClient {
+        HunterClient::with_base_url("test-key", format!("{}/v2/", server.uri()))
+            .expect("mock server uri should always be a valid base url")
+    }
+
+    #[tokio::test]
+    async fn fetches_the_account_quota() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v2/account"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(Account {
+                data: AccountData {
+                    first_name: Some("Jane".to_string()),
+                    last_name: Some("Doe".to_string()),
+                    email: "jane@example.com".to_string(),
+                    plan_name: "Free".to_string(),
+                    plan_level: 0,
+                    reset_date: "2021-01-01".to_string(),
+                    calls: AccountCalls { used: 5, available: 45 },
+                },
+            }))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let account = client.account().await.unwrap();
+
+        assert_eq!(account.data.email, "jane@example.com");
+        assert_eq!(account.data.calls.available, 45);
+    }
+}
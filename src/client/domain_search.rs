@@ -0,0 +1,250 @@
+use serde::{Deserialize, Serialize};
+
+use super::{HunterClient, FREE_PLAN_LIMIT};
+use crate::error::HunterError;
+
+impl HunterClient {
+    /// Look up the emails hunter.io has indexed for `domain`.
+    pub async fn domain_search(
+        &self,
+        domain: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Hunter, HunterError> {
+        let url = self.url(
+            "domain-search",
+            &[
+                ("domain", domain.to_string()),
+                ("limit", limit.to_string()),
+                ("offset", offset.to_string()),
+            ],
+        );
+
+        self.get(url).await
+    }
+
+    /// Fetch every email hunter.io has indexed for `domain`, issuing as many
+    /// paginated `domain-search` requests as needed.
+    ///
+    /// Stops once `meta.results` has been covered or `max_records` emails
+    /// have been collected, whichever comes first. If the account's plan
+    /// rejects the requested `limit` (a 403 past the free-plan cap), the
+    /// search is restarted once with `limit` clamped to [`FREE_PLAN_LIMIT`].
+    pub async fn domain_search_all(
+        &self,
+        domain: &str,
+        limit: usize,
+        max_records: usize,
+    ) -> Result<Hunter, HunterError> {
+        match self.paginate(domain, limit, max_records).await {
+            Err(HunterError::Api { code: 403, .. }) if limit > FREE_PLAN_LIMIT => {
+                self.paginate(domain, FREE_PLAN_LIMIT, max_records).await
+            }
+            result => result,
+        }
+    }
+
+    async fn paginate(
+        &self,
+        domain: &str,
+        limit: usize,
+        max_records: usize,
+    ) -> Result<Hunter, HunterError> {
+        let mut offset = 0;
+        let mut first: Option<Hunter> = None;
+
+        loop {
+            let page = self.domain_search(domain, limit, offset).await?;
+
+            let page = match first.as_mut() {
+                None => {
+                    first = Some(page);
+                    first.as_mut().unwrap()
+                }
+                Some(acc) => {
+                    acc.data.emails.extend(page.data.emails);
+                    acc
+                }
+            };
+
+            offset += limit;
+            if offset >= page.meta.results || page.data.emails.len() >= max_records {
+                break;
+            }
+        }
+
+        let mut hunter = first.expect("loop always runs at least once");
+        hunter.data.emails.truncate(max_records);
+        Ok(hunter)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Hunter {
+    pub data: Data,
+    pub meta: Meta,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Meta {
+    pub results: usize,
+    pub limit: usize,
+    pub offset: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Data {
+    pub domain: String,
+    pub disposable: bool,
+    pub webmail: bool,
+    pub accept_all: bool,
+    pub pattern: Option<String>,
+    pub organization: Option<String>,
+    pub country: Option<String>,
+    pub state: Option<String>,
+    pub emails: Vec<Email>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Email {
+    pub value: String,
+    pub r#type: String,
+    pub confidence: usize,
+    pub sources: Vec<Source>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub position: Option<String>,
+    pub seniority: Option<String>,
+    pub department: Option<String>,
+    pub linkedin: Option<String>,
+    pub twitter: Option<String>,
+    pub phone_number: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Source {
+    pub domain: String,
+    pub uri: String,
+    pub extracted_on: String,
+    pub last_seen_on: String,
+    pub still_on_page: bool,
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[derive(serde::Serialize)]
+    struct ErrorBody {
+        errors: Vec<ErrorDetail>,
+    }
+
+    #[derive(serde::Serialize)]
+    struct ErrorDetail {
+        details: String,
+    }
+
+    fn page(domain: &str, emails: usize, results: usize, limit: usize, offset: usize) -> Hunter {
+        Hunter {
+            data: Data {
+                domain: domain.to_string(),
+                disposable: false,
+                webmail: false,
+                accept_all: false,
+                pattern: None,
+                organization: None,
+                country: None,
+                state: None,
+                emails: (0..emails)
+                    .map(|i| Email {
+                        value: format!("user{}@{}", offset + i, domain),
+                        r#type: "personal".to_string(),
+                        confidence: 90,
+                        sources: vec![],
+                        first_name: None,
+                        last_name: None,
+                        position: None,
+                        seniority: None,
+                        department: None,
+                        linkedin: None,
+                        twitter: None,
+                        phone_number: None,
+                    })
+                    .collect(),
+            },
+            meta: Meta { results, limit, offset },
+        }
+    }
+
+    fn client_for(server: &MockServer) -> HunterClient {
+        HunterClient::with_base_url("test-key", format!("{}/v2/", server.uri()))
+            .expect("mock server uri should always be a valid base url")
+    }
+
+    #[tokio::test]
+    async fn paginates_past_the_first_page() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v2/domain-search"))
+            .and(query_param("offset", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page("example.com", 10, 15, 10, 0)))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/domain-search"))
+            .and(query_param("offset", "10"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page("example.com", 5, 15, 10, 10)))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let hunter = client.domain_search_all("example.com", 10, 100).await.unwrap();
+
+        assert_eq!(hunter.data.emails.len(), 15);
+    }
+
+    #[tokio::test]
+    async fn stops_once_max_records_is_reached() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v2/domain-search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page("example.com", 10, 1000, 10, 0)))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let hunter = client.domain_search_all("example.com", 10, 25).await.unwrap();
+
+        assert_eq!(hunter.data.emails.len(), 25);
+    }
+
+    #[tokio::test]
+    async fn retries_with_free_plan_limit_after_403() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v2/domain-search"))
+            .and(query_param("limit", "100"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(ErrorBody {
+                errors: vec![ErrorDetail {
+                    details: "limit exceeds your plan".to_string(),
+                }],
+            }))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/domain-search"))
+            .and(query_param("limit", "10"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page("example.com", 3, 3, 10, 0)))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let hunter = client.domain_search_all("example.com", 100, 100).await.unwrap();
+
+        assert_eq!(hunter.data.emails.len(), 3);
+    }
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+use super::HunterClient;
+use crate::error::HunterError;
+
+impl HunterClient {
+    /// Predict the most likely email address for a person at `domain` given
+    /// their first and last name.
+    pub async fn email_finder(
+        &self,
+        domain: &str,
+        first_name: &str,
+        last_name: &str,
+    ) -> Result<EmailFinder, HunterError> {
+        let url = self.url(
+            "email-finder",
+            &[
+                ("domain", domain.to_string()),
+                ("first_name", first_name.to_string()),
+                ("last_name", last_name.to_string()),
+            ],
+        );
+
+        self.get(url).await
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EmailFinder {
+    pub data: EmailFinderData,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EmailFinderData {
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub email: Option<String>,
+    pub score: Option<usize>,
+    pub domain: String,
+    pub accept_all: bool,
+    pub position: Option<String>,
+    pub twitter: Option<String>,
+    pub linkedin_url: Option<String>,
+    pub phone_number: Option<String>,
+    pub company: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn client_for(server: &MockServer) -> HunterClient {
+        HunterClient::with_base_url("test-key", format!("{}/v2/", server.uri()))
+            .expect("mock server uri should always be a valid base url")
+    }
+
+    #[tokio::test]
+    async fn predicts_an_email_address() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v2/email-finder"))
+            .and(query_param("domain", "stripe.com"))
+            .and(query_param("first_name", "Jane"))
+            .and(query_param("last_name", "Doe"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(EmailFinder {
+                data: EmailFinderData {
+                    first_name: Some("Jane".to_string()),
+                    last_name: Some("Doe".to_string()),
+                    email: Some("jane.doe@stripe.com".to_string()),
+                    score: Some(97),
+                    domain: "stripe.com".to_string(),
+                    accept_all: false,
+                    position: None,
+                    twitter: None,
+                    linkedin_url: None,
+                    phone_number: None,
+                    company: None,
+                },
+            }))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let found = client.email_finder("stripe.com", "Jane", "Doe").await.unwrap();
+
+        assert_eq!(found.data.email.as_deref(), Some("jane.doe@stripe.com"));
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+use super::HunterClient;
+use crate::error::HunterError;
+
+impl HunterClient {
+    /// Check the deliverability of a single email address.
+    pub async fn email_verifier(&self, email: &str) -> Result<EmailVerifier, HunterError> {
+        let url = self.url("email-verifier", &[("email", email.to_string())]);
+
+        self.get(url).await
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EmailVerifier {
+    pub data: EmailVerifierData,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EmailVerifierData {
+    pub status: String,
+    pub result: String,
+    pub score: usize,
+    pub email: String,
+    pub regexp: bool,
+    pub gibberish: bool,
+    pub disposable: bool,
+    pub webmail: bool,
+    pub mx_records: bool,
+    pub smtp_server: bool,
+    pub smtp_check: bool,
+    pub accept_all: bool,
+    pub block: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn client_for(server: &MockServer) -> HunterClient {
+        HunterClient::with_base_url("test-key", format!("{}/v2/", server.uri()))
+            .expect("mock server uri should always be a valid base url")
+    }
+
+    #[tokio::test]
+    async fn verifies_an_email_address() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v2/email-verifier"))
+            .and(query_param("email", "jane.doe@stripe.com"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(EmailVerifier {
+                data: EmailVerifierData {
+                    status: "valid".to_string(),
+                    result: "deliverable".to_string(),
+                    score: 97,
+                    email: "jane.doe@stripe.com".to_string(),
+                    regexp: true,
+                    gibberish: false,
+                    disposable: false,
+                    webmail: false,
+                    mx_records: true,
+                    smtp_server: true,
+                    smtp_check: true,
+                    accept_all: false,
+                    block: false,
+                },
+            }))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let verified = client.email_verifier("jane.doe@stripe.com").await.unwrap();
+
+        assert_eq!(verified.data.status, "valid");
+        assert_eq!(verified.data.result, "deliverable");
+    }
+}
@@ -0,0 +1,338 @@
+mod account;
+mod domain_search;
+mod email_finder;
+mod email_verifier;
+
+pub use account::Account;
+pub use domain_search::{Data, Email, Hunter, Meta, Source};
+pub use email_finder::EmailFinder;
+pub use email_verifier::EmailVerifier;
+
+use rand::Rng;
+use serde::Deserialize;
+use std::net::SocketAddr;
+use std::time::Duration;
+use url::Url;
+
+use crate::error::HunterError;
+use crate::rate_limiter::RateLimiter;
+
+const DEFAULT_BASE_URL: &str = "https://api.hunter.io/v2/";
+const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_USER_AGENT: &str = concat!("hunter-rs/", env!("CARGO_PKG_VERSION"));
+
+/// hunter.io rejects any `limit` above 10 for free-plan API keys.
+const FREE_PLAN_LIMIT: usize = 10;
+
+/// Builds a [`HunterClient`] with optional retry, throttling, and transport
+/// behavior.
+#[derive(Debug)]
+pub struct HunterClientBuilder {
+    api_key: String,
+    base_url: String,
+    max_retries: u32,
+    rate_limit: Option<u32>,
+    proxy: Option<String>,
+    resolve_overrides: Vec<(String, SocketAddr)>,
+    timeout: Option<Duration>,
+}
+
+impl HunterClientBuilder {
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            max_retries: DEFAULT_MAX_RETRIES,
+            rate_limit: None,
+            proxy: None,
+            resolve_overrides: Vec::new(),
+            timeout: None,
+        }
+    }
+
+    /// Target a custom base URL, useful for testing against a mock server.
+    /// Must end with a trailing slash (e.g. `"http://127.0.0.1:1234/"`) and
+    /// is validated, along with every other setting, in [`build`](Self::build).
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// How many times to retry a request that hit a 429 or 5xx, on top of
+    /// the initial attempt. Defaults to 3.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Throttle outgoing requests to at most `per_second` using a
+    /// token-bucket, so long CSV runs don't trip hunter.io's rate limit.
+    pub fn rate_limit(mut self, per_second: u32) -> Self {
+        self.rate_limit = Some(per_second);
+        self
+    }
+
+    /// Route all outbound requests through an HTTP(S) proxy.
+    pub fn proxy(mut self, proxy_url: impl Into<String>) -> Self {
+        self.proxy = Some(proxy_url.into());
+        self
+    }
+
+    /// Pin `host` to resolve to `addr` instead of going through DNS.
+    pub fn resolve(mut self, host: impl Into<String>, addr: SocketAddr) -> Self {
+        self.resolve_overrides.push((host.into(), addr));
+        self
+    }
+
+    /// Overall per-request timeout. Defaults to `reqwest`'s built-in default.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn build(self) -> Result<HunterClient, HunterError> {
+        let mut http = reqwest::Client::builder().user_agent(DEFAULT_USER_AGENT);
+
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(proxy_url) = self.proxy {
+            http = http.proxy(reqwest::Proxy::all(proxy_url)?);
+        }
+        for (host, addr) in self.resolve_overrides {
+            http = http.resolve(&host, addr);
+        }
+
+        if !self.base_url.ends_with('/') {
+            return Err(HunterError::InvalidBaseUrl(format!(
+                "{} must end with a trailing slash",
+                self.base_url
+            )));
+        }
+
+        Ok(HunterClient {
+            api_key: self.api_key,
+            base_url: Url::parse(&self.base_url)
+                .map_err(|e| HunterError::InvalidBaseUrl(e.to_string()))?,
+            max_retries: self.max_retries,
+            rate_limiter: self.rate_limit.map(RateLimiter::new),
+            http: http.build()?,
+        })
+    }
+}
+
+/// A client for the hunter.io API.
+///
+/// Holds the API key and a single `reqwest::Client` so the connection pool
+/// and TLS session are reused across requests instead of being rebuilt for
+/// every domain.
+#[derive(Debug)]
+pub struct HunterClient {
+    api_key: String,
+    base_url: Url,
+    max_retries: u32,
+    rate_limiter: Option<RateLimiter>,
+    http: reqwest::Client,
+}
+
+impl HunterClient {
+    /// Create a client targeting the default hunter.io API endpoint with
+    /// the default retry policy, no throttling, and the system DNS resolver.
+    pub fn new(api_key: impl Into<String>) -> Result<Self, HunterError> {
+        HunterClientBuilder::new(api_key).build()
+    }
+
+    /// Create a client targeting a custom base URL, useful for testing
+    /// against a mock server. Must end with a trailing slash and must parse
+    /// as a URL, or this returns [`HunterError::InvalidBaseUrl`].
+    pub fn with_base_url(
+        api_key: impl Into<String>,
+        base_url: impl Into<String>,
+    ) -> Result<Self, HunterError> {
+        HunterClientBuilder::new(api_key).base_url(base_url).build()
+    }
+
+    /// Start building a client with non-default retry or rate-limit settings.
+    pub fn builder(api_key: impl Into<String>) -> HunterClientBuilder {
+        HunterClientBuilder::new(api_key)
+    }
+
+    /// Build the request URL for `path` (relative to the base URL, no
+    /// leading slash) with `params` plus the API key attached as a query
+    /// string.
+    fn url(&self, path: &str, params: &[(&str, String)]) -> Url {
+        let mut url = self
+            .base_url
+            .join(path)
+            .expect("endpoint path should always be a valid relative url");
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("api_key", &self.api_key);
+            for (key, value) in params {
+                query.append_pair(key, value);
+            }
+        }
+        url
+    }
+
+    async fn get<T: serde::de::DeserializeOwned>(&self, url: Url) -> Result<T, HunterError> {
+        let mut attempt = 0;
+
+        loop {
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire().await;
+            }
+
+            let response = self.http.get(url.clone()).send().await?;
+            let status = response.status();
+
+            if status.as_u16() == 429 || status.is_server_error() {
+                if attempt >= self.max_retries {
+                    return if status.as_u16() == 429 {
+                        Err(HunterError::RateLimited)
+                    } else {
+                        Err(into_api_error(response).await)
+                    };
+                }
+
+                let delay = retry_after(&response).unwrap_or_else(|| backoff(attempt));
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+
+            if !status.is_success() {
+                return Err(into_api_error(response).await);
+            }
+
+            return response
+                .json::<T>()
+                .await
+                .map_err(|e| HunterError::Deserialize(e.to_string()));
+        }
+    }
+}
+
+/// Exponential backoff starting at 250ms and doubling each attempt, capped
+/// at 2s, with up to 100ms of jitter to avoid synchronized retries.
+fn backoff(attempt: u32) -> Duration {
+    let base = Duration::from_millis(250 * 2u64.pow(attempt)).min(Duration::from_secs(2));
+    let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..100));
+    base + jitter
+}
+
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    let value = response.headers().get(reqwest::header::RETRY_AFTER)?;
+    let seconds: u64 = value.to_str().ok()?.parse().ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+async fn into_api_error(response: reqwest::Response) -> HunterError {
+    let code = response.status().as_u16();
+    let details = match response.json::<ApiErrorBody>().await {
+        Ok(body) => body.errors.into_iter().map(|e| e.details).collect(),
+        Err(_) => vec!["no error details returned".to_string()],
+    };
+    HunterError::Api { code, details }
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiErrorBody {
+    errors: Vec<ApiErrorDetail>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiErrorDetail {
+    details: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn backoff_doubles_and_caps_at_two_seconds() {
+        for attempt in 0..3 {
+            let delay = backoff(attempt);
+            let base = Duration::from_millis(250 * 2u64.pow(attempt));
+            assert!(delay >= base, "attempt {}: {:?} should be >= {:?}", attempt, delay, base);
+            assert!(
+                delay < base + Duration::from_millis(100),
+                "attempt {}: {:?} should be within 100ms of jitter over {:?}",
+                attempt,
+                delay,
+                base
+            );
+        }
+
+        let capped = backoff(10);
+        assert!(capped >= Duration::from_secs(2));
+        assert!(capped < Duration::from_millis(2100));
+    }
+
+    fn empty_hunter() -> Hunter {
+        Hunter {
+            data: Data {
+                domain: "example.com".to_string(),
+                disposable: false,
+                webmail: false,
+                accept_all: false,
+                pattern: None,
+                organization: None,
+                country: None,
+                state: None,
+                emails: vec![],
+            },
+            meta: Meta { results: 0, limit: 10, offset: 0 },
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_after_429_and_honors_retry_after_header() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v2/domain-search"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/domain-search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(empty_hunter()))
+            .mount(&server)
+            .await;
+
+        let client = HunterClientBuilder::new("test-key")
+            .base_url(format!("{}/v2/", server.uri()))
+            .max_retries(2)
+            .build()
+            .unwrap();
+
+        let hunter = client.domain_search("example.com", 10, 0).await.unwrap();
+        assert_eq!(hunter.data.domain, "example.com");
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_retries_exhausted() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v2/domain-search"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = HunterClientBuilder::new("test-key")
+            .base_url(format!("{}/v2/", server.uri()))
+            .max_retries(1)
+            .build()
+            .unwrap();
+
+        let result = client.domain_search("example.com", 10, 0).await;
+        assert!(matches!(result, Err(HunterError::RateLimited)));
+    }
+}
@@ -0,0 +1,26 @@
+use thiserror::Error;
+
+/// Errors that can occur while talking to the hunter.io API.
+#[derive(Debug, Error)]
+pub enum HunterError {
+    /// The underlying HTTP request failed (connection, TLS, timeout, etc.).
+    #[error("http request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// hunter.io returned a non-2xx response with a structured error body.
+    #[error("hunter.io returned {code}: {}", .details.join(", "))]
+    Api { code: u16, details: Vec<String> },
+
+    /// The response body could not be deserialized into the expected shape.
+    #[error("failed to deserialize response: {0}")]
+    Deserialize(String),
+
+    /// hunter.io responded with 429 Too Many Requests.
+    #[error("rate limited by hunter.io")]
+    RateLimited,
+
+    /// The configured base URL was malformed, or missing its required
+    /// trailing slash.
+    #[error("invalid base url: {0}")]
+    InvalidBaseUrl(String),
+}
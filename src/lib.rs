@@ -0,0 +1,9 @@
+pub mod client;
+pub mod error;
+mod rate_limiter;
+
+pub use client::{
+    Account, Data, Email, EmailFinder, EmailVerifier, Hunter, HunterClient, HunterClientBuilder,
+    Meta, Source,
+};
+pub use error::HunterError;
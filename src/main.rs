@@ -1,45 +1,113 @@
 use csv::{Reader, WriterBuilder};
+use futures::stream::{self, StreamExt};
+use hunter::HunterClient;
 use serde::{Deserialize, Serialize};
 use std::env;
+use std::net::SocketAddr;
 use std::path::PathBuf;
 use structopt::StructOpt;
-use url::Url;
+use tokio::sync::Mutex;
 
 #[derive(Debug, StructOpt)]
-#[structopt(name = "hunter", about = "Read domains from a csv and extract email data from hunter.io.")]
+#[structopt(name = "hunter", about = "Extract email data from hunter.io.")]
 struct Opt {
+    #[structopt(flatten)]
+    client: ClientOpt,
+    #[structopt(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, StructOpt)]
+struct ClientOpt {
+    /// How many times to retry a request that hit a 429 or 5xx before giving up on it.
+    #[structopt(long, default_value = "3")]
+    max_retries: u32,
+    /// Throttle outgoing requests to at most N per second.
+    #[structopt(long)]
+    rate_limit: Option<u32>,
+    /// HTTP(S) proxy to route requests through, e.g. http://localhost:8080.
+    #[structopt(long)]
+    proxy: Option<String>,
+    /// Pin a hostname to an IP instead of going through DNS, as `host:ip` (port 443 assumed).
+    #[structopt(long, parse(try_from_str = parse_resolve))]
+    resolve: Vec<(String, SocketAddr)>,
+    /// Per-request timeout, in seconds.
+    #[structopt(long)]
+    timeout: Option<u64>,
+}
+
+fn parse_resolve(s: &str) -> Result<(String, SocketAddr), String> {
+    let (host, ip) = s
+        .split_once(':')
+        .ok_or_else(|| format!("expected host:ip, got `{}`", s))?;
+    let ip: std::net::IpAddr = ip.parse().map_err(|e| format!("invalid ip `{}`: {}", ip, e))?;
+    Ok((host.to_string(), SocketAddr::new(ip, 443)))
+}
+
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// Read domains from a csv and export every email hunter.io has indexed for them.
+    DomainSearch(DomainSearchOpt),
+    /// Predict the email address for a single person at a domain.
+    EmailFinder(EmailFinderOpt),
+    /// Check the deliverability of a single email address.
+    EmailVerifier(EmailVerifierOpt),
+    /// Show the account's remaining request quota.
+    Account(AccountOpt),
+}
+
+#[derive(Debug, StructOpt)]
+struct DomainSearchOpt {
     /// CSV file to load domains from.
     #[structopt(parse(from_os_str))]
     input: PathBuf,
     /// CSV file to write email to.
     #[structopt(parse(from_os_str))]
     output: PathBuf,
-    /// How many records to retreive. (If using a free plan, you must set to 10)
+    /// How many records to request per page. (If using a free plan, you must set to 10)
     #[structopt(short, long, default_value="100")]
     limit: usize,
+    /// Maximum number of emails to retrieve per domain, paginating past `limit` as needed.
+    #[structopt(short = "m", long, default_value="1000")]
+    max_records: usize,
+    /// How many domains to process at once.
+    #[structopt(short, long, default_value="1")]
+    concurrency: usize,
 }
 
-#[derive(Debug, Deserialize)]
-struct Domain {
-    name: String,
+#[derive(Debug, StructOpt)]
+struct EmailFinderOpt {
+    /// Domain to search, e.g. "stripe.com".
     domain: String,
+    /// Person's first name.
+    first_name: String,
+    /// Person's last name.
+    last_name: String,
+    /// CSV file to write the predicted address to. Prints to stdout if omitted.
+    #[structopt(short, long, parse(from_os_str))]
+    output: Option<PathBuf>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-struct Hunter {
-    data: Data,
-    meta: Meta,
+#[derive(Debug, StructOpt)]
+struct EmailVerifierOpt {
+    /// Email address to verify.
+    email: String,
+    /// CSV file to write the verification result to. Prints to stdout if omitted.
+    #[structopt(short, long, parse(from_os_str))]
+    output: Option<PathBuf>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-struct Meta {
-    results: usize,
-    limit: usize,
-    offset: usize,
+#[derive(Debug, StructOpt)]
+struct AccountOpt {}
+
+#[derive(Debug, Deserialize)]
+struct Domain {
+    name: String,
+    domain: String,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
-struct Data {
+struct DomainSearchRow {
     domain: String,
     disposable: bool,
     webmail: bool,
@@ -48,15 +116,9 @@ struct Data {
     organization: Option<String>,
     country: Option<String>,
     state: Option<String>,
-    emails: Vec<Email>,
-}
-
-#[derive(Debug, Deserialize, Serialize)]
-struct Email {
     value: String,
     r#type: String,
     confidence: usize,
-    sources: Vec<Source>,
     first_name: Option<String>,
     last_name: Option<String>,
     position: Option<String>,
@@ -68,35 +130,25 @@ struct Email {
 }
 
 #[derive(Debug, Deserialize, Serialize)]
-struct Source {
+struct EmailFinderRow {
     domain: String,
-    uri: String,
-    extracted_on: String,
-    last_seen_on: String,
-    still_on_page: bool,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    email: Option<String>,
+    score: Option<usize>,
+    position: Option<String>,
+    company: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
-struct Flattened {
-    domain: String,
+struct EmailVerifierRow {
+    email: String,
+    status: String,
+    result: String,
+    score: usize,
     disposable: bool,
     webmail: bool,
     accept_all: bool,
-    pattern: Option<String>,
-    organization: Option<String>,
-    country: Option<String>,
-    state: Option<String>,
-    value: String,
-    r#type: String,
-    confidence: usize,
-    first_name: Option<String>,
-    last_name: Option<String>,
-    position: Option<String>,
-    seniority: Option<String>,
-    department: Option<String>,
-    linkedin: Option<String>,
-    twitter: Option<String>,
-    phone_number: Option<String>,
 }
 
 fn get_api_key() -> String {
@@ -107,7 +159,7 @@ fn get_api_key() -> String {
         None => {
             eprintln!("Please set KEY (your hunter.io api key).");
             eprintln!("For example:");
-            eprintln!("  KEY=foo cargo run --release -- input.csv output.csv");
+            eprintln!("  KEY=foo cargo run --release -- domain-search input.csv output.csv");
             std::process::exit(1);
         }
     }
@@ -116,68 +168,167 @@ fn get_api_key() -> String {
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let api_key = get_api_key();
-
     let opt = Opt::from_args();
 
+    let mut builder = HunterClient::builder(api_key).max_retries(opt.client.max_retries);
+    if let Some(rate_limit) = opt.client.rate_limit {
+        builder = builder.rate_limit(rate_limit);
+    }
+    if let Some(proxy) = opt.client.proxy {
+        builder = builder.proxy(proxy);
+    }
+    if let Some(timeout) = opt.client.timeout {
+        builder = builder.timeout(std::time::Duration::from_secs(timeout));
+    }
+    for (host, addr) in opt.client.resolve {
+        builder = builder.resolve(host, addr);
+    }
+    let client = builder.build()?;
+
+    match opt.command {
+        Command::DomainSearch(opt) => domain_search(&client, opt).await,
+        Command::EmailFinder(opt) => email_finder(&client, opt).await,
+        Command::EmailVerifier(opt) => email_verifier(&client, opt).await,
+        Command::Account(opt) => account(&client, opt).await,
+    }
+}
+
+async fn domain_search(client: &HunterClient, opt: DomainSearchOpt) -> Result<(), Box<dyn std::error::Error>> {
+    let limit = opt.limit;
+    let max_records = opt.max_records;
+    let concurrency = opt.concurrency;
+
     let mut rdr = Reader::from_path(opt.input)?;
-    let mut wtr = WriterBuilder::new()
-        .has_headers(true)
-        .from_path(opt.output)?;
-
-    for result in rdr.deserialize() {
-        let domain: Domain = result?;
-        println!("processing {}...", &domain.domain);
-        let hunter_url = Url::parse_with_params("https://api.hunter.io/v2/domain-search",
-            &[("domain", &domain.domain), ("api_key", &api_key), ("limit", &opt.limit.to_string())])?;
-        let response = reqwest::get(hunter_url).await;
-        match response {
-            Ok(r) => match r.json::<Hunter>().await {
-                Ok(hunter) => {
-                    println!("{:?}", hunter.meta);
-                    let domain = hunter.data.domain.to_string();
-                    let disposable = hunter.data.disposable;
-                    let webmail = hunter.data.webmail;
-                    let accept_all = hunter.data.accept_all;
-                    let pattern = hunter.data.pattern.clone();
-                    let organization = hunter.data.organization.clone();
-                    let country = hunter.data.country.clone();
-                    let state = hunter.data.state.clone();
-                    for email in hunter.data.emails {
-                        let flattened = Flattened{
-                            domain: domain.to_string(),
-                            disposable,
-                            webmail,
-                            accept_all,
-                            pattern: pattern.clone(),
-                            organization: organization.clone(),
-                            country: country.clone(),
-                            state: state.clone(),
-                            value: email.value,
-                            r#type: email.r#type,
-                            confidence: email.confidence,
-                            first_name: email.first_name,
-                            last_name: email.last_name,
-                            position: email.position,
-                            seniority: email.seniority,
-                            department: email.department,
-                            linkedin: email.linkedin,
-                            twitter: email.twitter,
-                            phone_number: email.phone_number,
-                        };
-                        wtr.serialize(flattened)?;
+    let domains: Vec<Domain> = rdr.deserialize().collect::<Result<_, _>>()?;
+
+    let wtr = Mutex::new(WriterBuilder::new().has_headers(true).from_path(opt.output)?);
+    let failed_domains = Mutex::new(Vec::new());
+
+    stream::iter(domains)
+        .for_each_concurrent(concurrency, |domain| {
+            let wtr = &wtr;
+            let failed_domains = &failed_domains;
+            async move {
+                println!("processing {}...", &domain.domain);
+                match client.domain_search_all(&domain.domain, limit, max_records).await {
+                    Ok(hunter) => {
+                        println!("{:?}", hunter.meta);
+                        let data = hunter.data;
+                        let mut wtr = wtr.lock().await;
+                        for email in data.emails {
+                            let row = DomainSearchRow {
+                                domain: data.domain.clone(),
+                                disposable: data.disposable,
+                                webmail: data.webmail,
+                                accept_all: data.accept_all,
+                                pattern: data.pattern.clone(),
+                                organization: data.organization.clone(),
+                                country: data.country.clone(),
+                                state: data.state.clone(),
+                                value: email.value,
+                                r#type: email.r#type,
+                                confidence: email.confidence,
+                                first_name: email.first_name,
+                                last_name: email.last_name,
+                                position: email.position,
+                                seniority: email.seniority,
+                                department: email.department,
+                                linkedin: email.linkedin,
+                                twitter: email.twitter,
+                                phone_number: email.phone_number,
+                            };
+                            if let Err(e) = wtr.serialize(row) {
+                                eprintln!("error writing row for {}: {}", &data.domain, e);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("giving up on {} after retries: {}", &domain.domain, e);
+                        failed_domains.lock().await.push(domain.domain);
                     }
-                },
-                Err(e) => {
-                    eprintln!("error: {}", e);
-                    std::process::exit(1);
                 }
-            },
-            Err(e) => {
-                eprintln!("error: {}", e);
-                std::process::exit(1);
             }
+        })
+        .await;
+
+    wtr.lock().await.flush()?;
+
+    let failed_domains = failed_domains.into_inner();
+    if !failed_domains.is_empty() {
+        eprintln!("skipped {} domain(s): {}", failed_domains.len(), failed_domains.join(", "));
+    }
+    Ok(())
+}
+
+async fn email_finder(client: &HunterClient, opt: EmailFinderOpt) -> Result<(), Box<dyn std::error::Error>> {
+    let found = match client.email_finder(&opt.domain, &opt.first_name, &opt.last_name).await {
+        Ok(found) => found,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let row = EmailFinderRow {
+        domain: found.data.domain,
+        first_name: found.data.first_name,
+        last_name: found.data.last_name,
+        email: found.data.email,
+        score: found.data.score,
+        position: found.data.position,
+        company: found.data.company,
+    };
+
+    match opt.output {
+        Some(path) => {
+            let mut wtr = WriterBuilder::new().has_headers(true).from_path(path)?;
+            wtr.serialize(row)?;
+            wtr.flush()?;
+        }
+        None => println!("{:?}", row),
+    }
+    Ok(())
+}
+
+async fn email_verifier(client: &HunterClient, opt: EmailVerifierOpt) -> Result<(), Box<dyn std::error::Error>> {
+    let verified = match client.email_verifier(&opt.email).await {
+        Ok(verified) => verified,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let row = EmailVerifierRow {
+        email: verified.data.email,
+        status: verified.data.status,
+        result: verified.data.result,
+        score: verified.data.score,
+        disposable: verified.data.disposable,
+        webmail: verified.data.webmail,
+        accept_all: verified.data.accept_all,
+    };
+
+    match opt.output {
+        Some(path) => {
+            let mut wtr = WriterBuilder::new().has_headers(true).from_path(path)?;
+            wtr.serialize(row)?;
+            wtr.flush()?;
         }
+        None => println!("{:?}", row),
     }
-    wtr.flush()?;
+    Ok(())
+}
+
+async fn account(client: &HunterClient, _opt: AccountOpt) -> Result<(), Box<dyn std::error::Error>> {
+    let account = match client.account().await {
+        Ok(account) => account,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    println!(
+        "{} requests used, {} remaining (resets {})",
+        account.data.calls.used, account.data.calls.available, account.data.reset_date
+    );
     Ok(())
 }
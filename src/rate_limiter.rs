@@ -0,0 +1,32 @@
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// A simple token-bucket limiter that throttles callers to at most `N`
+/// acquisitions per second, used to keep bulk CSV runs under hunter.io's
+/// per-second request cap.
+#[derive(Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    pub fn new(per_second: u32) -> Self {
+        let interval = Duration::from_secs_f64(1.0 / per_second.max(1) as f64);
+        Self {
+            interval,
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Block until a token is available, then reserve the next one.
+    pub async fn acquire(&self) {
+        let mut next_slot = self.next_slot.lock().await;
+        let now = Instant::now();
+        if *next_slot > now {
+            tokio::time::sleep(*next_slot - now).await;
+        }
+        *next_slot = (*next_slot).max(now) + self.interval;
+    }
+}